@@ -0,0 +1,142 @@
+use soroban_sdk::{panic_with_error, symbol_short, Address, BytesN, Env, String, Symbol};
+
+use crate::error::Error;
+use crate::oracle::Oracle;
+
+const ADMIN: Symbol = symbol_short!("admin");
+const NAME: Symbol = symbol_short!("name");
+const SYMBOL: Symbol = symbol_short!("symbol");
+const DECIMALS: Symbol = symbol_short!("decimals");
+
+/// Approximate ledgers per day at a 5-second close time.
+const DAY_IN_LEDGERS: u32 = 17280;
+/// How far a balance entry's TTL is extended on each touch.
+const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+/// Only re-extend once the remaining TTL drops below this, to avoid paying
+/// for an extend on every single access.
+const BALANCE_BUMP_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub struct Admin;
+
+impl Admin {
+    pub fn initialize(
+        env: &Env,
+        admin: &Address,
+        asset_contract: &Address,
+        pegged_asset: &Symbol,
+        name: &String,
+        symbol: &String,
+        decimals: u32,
+    ) {
+        env.storage().instance().set(&ADMIN, admin);
+        env.storage().instance().set(&NAME, name);
+        env.storage().instance().set(&SYMBOL, symbol);
+        env.storage().instance().set(&DECIMALS, &decimals);
+        Oracle::initialize(env, asset_contract, pegged_asset);
+    }
+
+    pub fn get_admin(env: &Env) -> Address {
+        env.storage().instance().get(&ADMIN).unwrap()
+    }
+
+    pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+        Self::get_admin(env).require_auth();
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    pub fn mint(env: &Env, to: &Address, amount: i128) {
+        Self::get_admin(env).require_auth();
+        Self::credit(env, to, amount);
+    }
+
+    pub fn clawback(env: &Env, from: &Address, amount: i128) {
+        Self::get_admin(env).require_auth();
+        Self::debit(env, from, amount);
+    }
+
+    pub fn set_authorized(env: &Env, id: &Address, authorize: bool) {
+        Self::get_admin(env).require_auth();
+        env.storage().persistent().set(&Self::auth_key(id), &authorize);
+    }
+
+    pub fn authorized(env: &Env, id: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&Self::auth_key(id))
+            .unwrap_or(true)
+    }
+
+    fn auth_key(id: &Address) -> (Symbol, Address) {
+        (symbol_short!("auth"), id.clone())
+    }
+
+    pub(crate) fn balance_key(id: &Address) -> (Symbol, Address) {
+        (symbol_short!("balance"), id.clone())
+    }
+
+    /// Bump a balance entry's TTL so frequently used accounts stay live while
+    /// idle ones expire. `extend_ttl` requires the entry to already exist;
+    /// callers with a never-funded address should check first.
+    pub(crate) fn bump_balance(env: &Env, id: &Address) {
+        env.storage().persistent().extend_ttl(
+            &Self::balance_key(id),
+            BALANCE_BUMP_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+    }
+
+    pub(crate) fn name(env: &Env) -> String {
+        env.storage().instance().get(&NAME).unwrap()
+    }
+
+    pub(crate) fn symbol(env: &Env) -> String {
+        env.storage().instance().get(&SYMBOL).unwrap()
+    }
+
+    pub(crate) fn decimals(env: &Env) -> u32 {
+        env.storage().instance().get(&DECIMALS).unwrap()
+    }
+
+    /// Credit a balance, used by both admin minting and the bridge's wrapped-asset mint.
+    ///
+    /// `amount` must not be negative: a negative amount here would silently
+    /// debit the recipient instead of crediting it. Zero is accepted as a
+    /// no-op, matching SEP-41's treatment of zero-amount transfers/mints.
+    pub(crate) fn credit(env: &Env, to: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        if amount == 0 {
+            return;
+        }
+        let key = Self::balance_key(to);
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let new_balance = balance
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticError));
+        env.storage().persistent().set(&key, &new_balance);
+        Self::bump_balance(env, to);
+    }
+
+    /// Debit a balance, used by both admin clawback and the bridge's lock/escrow.
+    ///
+    /// `amount` must not be negative: a negative amount would pass the
+    /// `balance < amount` check trivially and then *increase* the balance.
+    /// Zero is accepted as a no-op, matching SEP-41's treatment of
+    /// zero-amount transfers/burns.
+    pub(crate) fn debit(env: &Env, from: &Address, amount: i128) {
+        if amount < 0 {
+            panic_with_error!(env, Error::InvalidAmount);
+        }
+        if amount == 0 {
+            return;
+        }
+        let key = Self::balance_key(from);
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if balance < amount {
+            panic_with_error!(env, Error::InsufficientBalance);
+        }
+        env.storage().persistent().set(&key, &(balance - amount));
+        Self::bump_balance(env, from);
+    }
+}
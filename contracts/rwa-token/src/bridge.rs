@@ -0,0 +1,172 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, ToXdr, Vec};
+
+use crate::admin::Admin;
+use crate::error::Error;
+
+const GUARDIANS: Symbol = symbol_short!("guardian");
+const THRESHOLD: Symbol = symbol_short!("threshold");
+const SEQUENCE: Symbol = symbol_short!("seq");
+
+/// A locked-for-transfer record, keyed by sequence number.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferRecord {
+    pub from: Address,
+    pub amount: i128,
+    pub target_chain: u32,
+    pub target_address: BytesN<32>,
+}
+
+/// Metadata describing the origin of a wrapped RWA asset held by this contract.
+pub struct WrappedAssetMeta {
+    pub origin_chain: u32,
+    pub origin_address: BytesN<32>,
+    pub decimals: u32,
+}
+
+pub struct Bridge;
+
+impl Bridge {
+    /// Admin-only: set the guardian public keys and the signature threshold (M-of-N).
+    pub fn set_guardians(env: &Env, guardians: Vec<BytesN<32>>, threshold: u32) {
+        Admin::get_admin(env).require_auth();
+        env.storage().instance().set(&GUARDIANS, &guardians);
+        env.storage().instance().set(&THRESHOLD, &threshold);
+    }
+
+    fn guardians(env: &Env) -> Vec<BytesN<32>> {
+        env.storage().instance().get(&GUARDIANS).unwrap()
+    }
+
+    fn threshold(env: &Env) -> u32 {
+        env.storage().instance().get(&THRESHOLD).unwrap()
+    }
+
+    fn next_sequence(env: &Env) -> u64 {
+        let next: u64 = env.storage().instance().get(&SEQUENCE).unwrap_or(0);
+        env.storage().instance().set(&SEQUENCE, &(next + 1));
+        next
+    }
+
+    fn transfer_key(sequence: u64) -> (Symbol, u64) {
+        (symbol_short!("xfer"), sequence)
+    }
+
+    fn processed_key(source_chain: u32, sequence: u64) -> (Symbol, u32, u64) {
+        (symbol_short!("seen"), source_chain, sequence)
+    }
+
+    fn wrapped_key(origin_chain: u32, origin_address: &BytesN<32>) -> (Symbol, u32, BytesN<32>) {
+        (symbol_short!("wrapped"), origin_chain, origin_address.clone())
+    }
+
+    /// Deterministically rebuild the signed statement from the call arguments
+    /// themselves, rather than trusting a caller-supplied payload blob. If the
+    /// payload were accepted as a separate parameter, any previously
+    /// guardian-signed payload could be replayed with an attacker-chosen
+    /// `recipient`/`amount` under a fresh, unused `(source_chain, sequence)`.
+    fn build_payload(
+        env: &Env,
+        source_chain: u32,
+        sequence: u64,
+        recipient: &Address,
+        amount: i128,
+        asset_meta: &WrappedAssetMeta,
+    ) -> Bytes {
+        let mut payload = Bytes::from_array(env, &source_chain.to_be_bytes());
+        payload.append(&Bytes::from_array(env, &sequence.to_be_bytes()));
+        payload.append(&recipient.to_xdr(env));
+        payload.append(&Bytes::from_array(env, &amount.to_be_bytes()));
+        payload.append(&Bytes::from_array(env, &asset_meta.origin_chain.to_be_bytes()));
+        payload.append(&Bytes::from_array(env, &asset_meta.origin_address.to_array()));
+        payload.append(&Bytes::from_array(env, &asset_meta.decimals.to_be_bytes()));
+        payload
+    }
+
+    /// Lock (escrow/burn) `amount` from `from` for transfer to `target_address` on
+    /// `target_chain`, returning the sequence number guardians must attest to.
+    ///
+    /// Routed through `Oracle::check_compliance_before_transfer` so leaving
+    /// value through the bridge counts against `from`'s rate limit the same
+    /// way an ordinary transfer would.
+    pub fn lock_for_transfer(
+        env: &Env,
+        from: Address,
+        amount: i128,
+        target_chain: u32,
+        target_address: BytesN<32>,
+    ) -> Result<u64, Error> {
+        from.require_auth();
+        crate::oracle::Oracle::check_compliance_before_transfer(env, &from, &from, amount)?;
+        Admin::debit(env, &from, amount);
+
+        let sequence = Self::next_sequence(env);
+        let record = TransferRecord {
+            from,
+            amount,
+            target_chain,
+            target_address,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::transfer_key(sequence), &record);
+        Ok(sequence)
+    }
+
+    /// Complete a cross-chain transfer attested by at least `threshold` guardians.
+    ///
+    /// The signed statement is `(source_chain, sequence, recipient, amount,
+    /// asset_meta)`, rebuilt here rather than taken as a caller-supplied
+    /// payload so a signature can't be replayed against different call
+    /// arguments. `signatures` are `(guardian_index, signature)` pairs over
+    /// that statement's keccak256 hash; duplicate guardian indices count only
+    /// once towards the threshold. Rejects replays and mints the attested
+    /// amount to `recipient` once the threshold is met, recording
+    /// wrapped-asset metadata.
+    pub fn complete_transfer(
+        env: &Env,
+        source_chain: u32,
+        sequence: u64,
+        recipient: Address,
+        amount: i128,
+        asset_meta: WrappedAssetMeta,
+        signatures: Vec<(u32, BytesN<64>)>,
+    ) -> Result<(), Error> {
+        let processed_key = Self::processed_key(source_chain, sequence);
+        if env.storage().persistent().has(&processed_key) {
+            return Err(Error::TransferAlreadyProcessed);
+        }
+
+        let payload = Self::build_payload(env, source_chain, sequence, &recipient, amount, &asset_meta);
+        let digest = env.crypto().keccak256(&payload);
+        let message = Bytes::from_array(env, &digest.to_array());
+        let guardians = Self::guardians(env);
+
+        let mut seen_indices: Vec<u32> = Vec::new(env);
+        let mut verified = 0u32;
+        for (guardian_index, signature) in signatures.iter() {
+            if seen_indices.contains(&guardian_index) {
+                continue;
+            }
+            let guardian = guardians
+                .get(guardian_index)
+                .ok_or(Error::InvalidGuardianSignature)?;
+            env.crypto()
+                .ed25519_verify(&guardian, &message, &signature);
+            seen_indices.push_back(guardian_index);
+            verified += 1;
+        }
+
+        if verified < Self::threshold(env) {
+            return Err(Error::GuardianThresholdNotMet);
+        }
+
+        env.storage().persistent().set(&processed_key, &true);
+        env.storage().persistent().set(
+            &Self::wrapped_key(asset_meta.origin_chain, &asset_meta.origin_address),
+            &asset_meta.decimals,
+        );
+        Admin::credit(env, &recipient, amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::admin::Admin;
+use crate::error::Error;
+
+const GLOBAL_LIMIT: Symbol = symbol_short!("gtxlimit");
+const GLOBAL_WINDOW: Symbol = symbol_short!("gtxwin");
+
+/// Default rolling-window length, in ledgers (~1 day at a 5-second close time).
+const DEFAULT_WINDOW_LEDGERS: u32 = 17280;
+
+pub struct Compliance;
+
+impl Compliance {
+    fn per_address_limit_key(addr: &Address) -> (Symbol, Address) {
+        (symbol_short!("txlimit"), addr.clone())
+    }
+
+    fn window_state_key(addr: &Address) -> (Symbol, Address) {
+        (symbol_short!("txwin"), addr.clone())
+    }
+
+    fn window_ledgers(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&GLOBAL_WINDOW)
+            .unwrap_or(DEFAULT_WINDOW_LEDGERS)
+    }
+
+    /// Admin-only: set the rolling window length, in ledgers.
+    pub fn set_window_ledgers(env: &Env, window_ledgers: u32) {
+        Admin::get_admin(env).require_auth();
+        env.storage().instance().set(&GLOBAL_WINDOW, &window_ledgers);
+    }
+
+    /// Admin-only: set the global default transfer-volume limit, in whole token
+    /// units (scaled internally by `decimals()`). `None` disables the global limit.
+    pub fn set_global_limit(env: &Env, limit: Option<i128>) {
+        Admin::get_admin(env).require_auth();
+        match limit {
+            Some(limit) => env.storage().instance().set(&GLOBAL_LIMIT, &limit),
+            None => env.storage().instance().remove(&GLOBAL_LIMIT),
+        }
+    }
+
+    /// Admin-only: set a per-address transfer-volume limit, in whole token units
+    /// (scaled internally by `decimals()`), overriding the global limit for `addr`.
+    /// `None` clears the override, falling back to the global limit.
+    pub fn set_address_limit(env: &Env, addr: &Address, limit: Option<i128>) {
+        Admin::get_admin(env).require_auth();
+        let key = Self::per_address_limit_key(addr);
+        match limit {
+            Some(limit) => env.storage().persistent().set(&key, &limit),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    fn limit_for(env: &Env, addr: &Address) -> Option<i128> {
+        let per_address: Option<i128> = env.storage().persistent().get(&Self::per_address_limit_key(addr));
+        let human_limit = per_address.or_else(|| env.storage().instance().get(&GLOBAL_LIMIT))?;
+        let decimals = Admin::decimals(env);
+        Some(human_limit.saturating_mul(10i128.pow(decimals)))
+    }
+
+    /// Accumulate `amount` into `from`'s rolling window, rejecting if it would
+    /// exceed the configured limit. A no-op if no limit is configured for `from`.
+    pub fn check_and_record(env: &Env, from: &Address, amount: i128) -> Result<(), Error> {
+        let Some(limit) = Self::limit_for(env, from) else {
+            return Ok(());
+        };
+
+        let key = Self::window_state_key(from);
+        let current_ledger = env.ledger().sequence();
+        let window_ledgers = Self::window_ledgers(env);
+
+        let (window_start, accumulated): (u32, i128) = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or((current_ledger, 0));
+
+        let (window_start, accumulated) = if current_ledger - window_start >= window_ledgers {
+            (current_ledger, 0)
+        } else {
+            (window_start, accumulated)
+        };
+
+        let new_accumulated = accumulated
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticError)?;
+        if new_accumulated > limit {
+            return Err(Error::TransferLimitExceeded);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&key, &(window_start, new_accumulated));
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::contracterror;
+
+/// Errors returned by the RWA token contract.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    ArithmeticError = 1,
+    ProofVerificationFailed = 2,
+    OracleStale = 3,
+    OracleConfidence = 4,
+    InsufficientBalance = 5,
+    GuardianThresholdNotMet = 6,
+    InvalidGuardianSignature = 7,
+    TransferAlreadyProcessed = 8,
+    TransferLimitExceeded = 9,
+    InvalidAmount = 10,
+    InvalidFeeConfig = 11,
+    ShieldedTransfersDisabled = 12,
+}
+
+impl Error {
+    /// Whether this error originates from oracle freshness/confidence gating,
+    /// so callers can branch on "is this an oracle problem" without matching
+    /// every variant individually.
+    pub fn is_oracle_error(&self) -> bool {
+        matches!(self, Error::OracleStale | Error::OracleConfidence)
+    }
+}
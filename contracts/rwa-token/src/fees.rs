@@ -0,0 +1,72 @@
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, Symbol};
+
+use crate::admin::Admin;
+use crate::error::Error;
+
+/// Basis-point denominator: `BasisPoints(10_000)` would charge 100% of the transfer.
+const BPS_DENOMINATOR: u32 = 10_000;
+
+const FEE_MODE: Symbol = symbol_short!("feemode");
+const TREASURY: Symbol = symbol_short!("treasury");
+
+/// Transfer-fee mode for RWA settlement.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeMode {
+    /// No fee is charged.
+    Disabled,
+    /// A flat fee charged per transfer, independent of amount.
+    Fixed(i128),
+    /// A fee proportional to the transferred amount, in basis points.
+    BasisPoints(u32),
+}
+
+pub struct Fees;
+
+impl Fees {
+    /// Admin-only: configure the fee mode and the treasury fees are routed to.
+    /// Pass `FeeMode::Disabled` to turn fees off.
+    pub fn set_fee(env: &Env, mode: FeeMode, treasury: Address) {
+        Admin::get_admin(env).require_auth();
+        match &mode {
+            FeeMode::Disabled => {}
+            FeeMode::Fixed(fee) if *fee >= 0 => {}
+            FeeMode::BasisPoints(bps) if *bps <= BPS_DENOMINATOR => {}
+            _ => panic_with_error!(env, Error::InvalidFeeConfig),
+        }
+        env.storage().instance().set(&FEE_MODE, &mode);
+        env.storage().instance().set(&TREASURY, &treasury);
+    }
+
+    /// The currently configured fee mode and treasury, if any.
+    pub fn get_fee(env: &Env) -> (FeeMode, Option<Address>) {
+        let mode = Self::mode(env);
+        let treasury = env.storage().instance().get(&TREASURY);
+        (mode, treasury)
+    }
+
+    fn mode(env: &Env) -> FeeMode {
+        env.storage()
+            .instance()
+            .get(&FEE_MODE)
+            .unwrap_or(FeeMode::Disabled)
+    }
+
+    /// The fee owed on a transfer of `amount`, given the configured mode.
+    ///
+    /// Clamped to `[0, amount]` so a misconfigured or stale mode (e.g. a fixed
+    /// fee larger than the amount being transferred) can never make the
+    /// post-fee remainder negative.
+    pub fn fee_for(env: &Env, amount: i128) -> i128 {
+        let fee = match Self::mode(env) {
+            FeeMode::Disabled => 0,
+            FeeMode::Fixed(fee) => fee,
+            FeeMode::BasisPoints(bps) => amount * bps as i128 / BPS_DENOMINATOR as i128,
+        };
+        fee.clamp(0, amount.max(0))
+    }
+
+    pub fn treasury(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&TREASURY)
+    }
+}
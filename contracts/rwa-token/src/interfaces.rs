@@ -0,0 +1,143 @@
+use soroban_sdk::{contractclient, panic_with_error, symbol_short, Address, Env, MuxedAddress, String, Symbol};
+
+use crate::admin::Admin;
+use crate::error::Error;
+
+/// Approximate ledgers per day at a 5-second close time.
+const DAY_IN_LEDGERS: u32 = 17280;
+/// How far an allowance entry's TTL is extended on each touch.
+const ALLOWANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+/// Only re-extend once the remaining TTL drops below this, to avoid paying
+/// for an extend on every single access.
+const ALLOWANCE_BUMP_THRESHOLD: u32 = ALLOWANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Standard SEP-0041 token interface.
+#[contractclient(name = "TokenClient")]
+pub trait TokenInterface {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128;
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, live_until_ledger: u32);
+    fn balance(env: Env, id: Address) -> i128;
+    fn transfer(env: Env, from: Address, to: MuxedAddress, amount: i128);
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128);
+    fn burn(env: Env, from: Address, amount: i128);
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128);
+    fn decimals(env: Env) -> u32;
+    fn name(env: Env) -> String;
+    fn symbol(env: Env) -> String;
+}
+
+pub struct TokenInterfaceImpl;
+
+impl TokenInterfaceImpl {
+    fn allowance_key(from: &Address, spender: &Address) -> (Symbol, Address, Address) {
+        (symbol_short!("allow"), from.clone(), spender.clone())
+    }
+
+    /// Bump an allowance entry's TTL so frequently used approvals stay live while idle ones expire.
+    fn bump_allowance(env: &Env, from: &Address, spender: &Address) {
+        env.storage().persistent().extend_ttl(
+            &Self::allowance_key(from, spender),
+            ALLOWANCE_BUMP_THRESHOLD,
+            ALLOWANCE_BUMP_AMOUNT,
+        );
+    }
+
+    pub fn allowance(env: &Env, from: &Address, spender: &Address) -> i128 {
+        let key = Self::allowance_key(from, spender);
+        let allowance: Option<(i128, u32)> = env.storage().persistent().get(&key);
+        match allowance {
+            Some((amount, live_until_ledger)) if live_until_ledger >= env.ledger().sequence() => {
+                Self::bump_allowance(env, from, spender);
+                amount
+            }
+            _ => 0,
+        }
+    }
+
+    pub fn approve(env: &Env, from: &Address, spender: &Address, amount: i128, live_until_ledger: u32) {
+        from.require_auth();
+        let key = Self::allowance_key(from, spender);
+        env.storage().persistent().set(&key, &(amount, live_until_ledger));
+        Self::bump_allowance(env, from, spender);
+    }
+
+    pub fn balance(env: &Env, id: &Address) -> i128 {
+        let key = Admin::balance_key(id);
+        let balance = env.storage().persistent().get(&key).unwrap_or(0);
+        // `extend_ttl` requires the entry to exist; a never-funded address has
+        // no balance entry to bump, so only bump when one is actually there.
+        if env.storage().persistent().has(&key) {
+            Admin::bump_balance(env, id);
+        }
+        balance
+    }
+
+    /// Route the configured transfer fee to the treasury, returning the remainder
+    /// that should be delivered to the recipient.
+    fn settle_fee(env: &Env, amount: i128) -> i128 {
+        let fee = crate::fees::Fees::fee_for(env, amount);
+        if fee > 0 {
+            if let Some(treasury) = crate::fees::Fees::treasury(env) {
+                Admin::credit(env, &treasury, fee);
+            }
+        }
+        amount - fee
+    }
+
+    pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+        from.require_auth();
+        Admin::debit(env, from, amount);
+        let remainder = Self::settle_fee(env, amount);
+        // A fee equal to the full amount leaves a zero remainder, which is a
+        // valid (if pointless) transfer outcome, not an error: Admin::credit
+        // rejects negative amounts but accepts zero as a no-op.
+        if remainder > 0 {
+            Admin::credit(env, to, remainder);
+        }
+    }
+
+    pub fn transfer_from(env: &Env, spender: &Address, from: &Address, to: &Address, amount: i128) {
+        spender.require_auth();
+        Self::spend_allowance(env, from, spender, amount);
+        Admin::debit(env, from, amount);
+        let remainder = Self::settle_fee(env, amount);
+        if remainder > 0 {
+            Admin::credit(env, to, remainder);
+        }
+    }
+
+    pub fn burn(env: &Env, from: &Address, amount: i128) {
+        from.require_auth();
+        Admin::debit(env, from, amount);
+    }
+
+    pub fn burn_from(env: &Env, spender: &Address, from: &Address, amount: i128) {
+        spender.require_auth();
+        Self::spend_allowance(env, from, spender, amount);
+        Admin::debit(env, from, amount);
+    }
+
+    fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+        let remaining = Self::allowance(env, from, spender);
+        if remaining < amount {
+            panic_with_error!(env, Error::InsufficientBalance);
+        }
+        let key = Self::allowance_key(from, spender);
+        let (_, live_until_ledger): (i128, u32) = env.storage().persistent().get(&key).unwrap();
+        env.storage()
+            .persistent()
+            .set(&key, &(remaining - amount, live_until_ledger));
+    }
+
+    pub fn decimals(env: &Env) -> u32 {
+        Admin::decimals(env)
+    }
+
+    pub fn name(env: &Env) -> String {
+        Admin::name(env)
+    }
+
+    pub fn symbol(env: &Env) -> String {
+        Admin::symbol(env)
+    }
+}
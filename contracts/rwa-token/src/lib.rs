@@ -0,0 +1,16 @@
+#![no_std]
+
+mod admin;
+mod bridge;
+mod compliance;
+mod error;
+mod fees;
+mod interfaces;
+mod oracle;
+mod rwa_oracle;
+mod shielded;
+mod token;
+mod zk_verifier;
+
+pub use error::Error;
+pub use token::RWATokenContract;
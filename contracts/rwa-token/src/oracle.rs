@@ -0,0 +1,128 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::error::Error;
+use crate::rwa_oracle::{OracleClient, PriceData, RWAAssetType, RWAMetadata, RegulatoryInfo};
+
+const ASSET_CONTRACT: Symbol = symbol_short!("astctrct");
+const PEGGED_ASSET: Symbol = symbol_short!("peg");
+const MAX_STALE: Symbol = symbol_short!("maxstale");
+const MIN_CONF: Symbol = symbol_short!("minconf");
+
+/// Default maximum age, in seconds, before a price observation is considered stale.
+const DEFAULT_MAX_STALENESS_SECS: u64 = 300;
+/// Default minimum cross-source agreement, in basis points, required to trust a price.
+const DEFAULT_MIN_CONFIDENCE_BPS: u32 = 9_000;
+
+pub struct Oracle;
+
+impl Oracle {
+    pub fn initialize(env: &Env, asset_contract: &Address, pegged_asset: &Symbol) {
+        env.storage().instance().set(&ASSET_CONTRACT, asset_contract);
+        env.storage().instance().set(&PEGGED_ASSET, pegged_asset);
+    }
+
+    pub fn get_asset_contract(env: &Env) -> Address {
+        env.storage().instance().get(&ASSET_CONTRACT).unwrap()
+    }
+
+    pub fn get_pegged_asset(env: &Env) -> Symbol {
+        env.storage().instance().get(&PEGGED_ASSET).unwrap()
+    }
+
+    fn client(env: &Env) -> OracleClient {
+        OracleClient::new(env, &Self::get_asset_contract(env))
+    }
+
+    /// Fetch the latest price with no freshness or confidence gating.
+    pub fn get_price(env: &Env) -> Result<PriceData, Error> {
+        Ok(Self::client(env).price(&Self::get_pegged_asset(env)))
+    }
+
+    /// Fetch the price as of a specific timestamp, with no freshness or confidence gating.
+    pub fn get_price_at(env: &Env, timestamp: u64) -> Result<PriceData, Error> {
+        Ok(Self::client(env).price_at(&Self::get_pegged_asset(env), &timestamp))
+    }
+
+    pub fn get_decimals(env: &Env) -> Result<u32, Error> {
+        Ok(Self::client(env).decimals())
+    }
+
+    pub fn get_rwa_metadata(env: &Env) -> Result<RWAMetadata, Error> {
+        Ok(Self::client(env).metadata(&Self::get_pegged_asset(env)))
+    }
+
+    pub fn get_asset_type(env: &Env) -> Result<RWAAssetType, Error> {
+        Ok(Self::client(env).asset_type(&Self::get_pegged_asset(env)))
+    }
+
+    pub fn is_regulated(env: &Env) -> Result<bool, Error> {
+        Ok(Self::get_regulatory_info(env)?.regulated)
+    }
+
+    pub fn get_regulatory_info(env: &Env) -> Result<RegulatoryInfo, Error> {
+        Ok(Self::client(env).regulatory_info(&Self::get_pegged_asset(env)))
+    }
+
+    /// Compliance checks run before a balance-moving transfer. Deliberately
+    /// independent of oracle health: transfers only move existing balance,
+    /// so a stale/low-confidence oracle must not block them.
+    pub fn check_compliance_before_transfer(
+        env: &Env,
+        from: &Address,
+        _to: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        crate::compliance::Compliance::check_and_record(env, from, amount)
+    }
+
+    // --- Staleness / confidence gating -------------------------------------------------
+
+    fn max_staleness_secs(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&MAX_STALE)
+            .unwrap_or(DEFAULT_MAX_STALENESS_SECS)
+    }
+
+    fn min_confidence_bps(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MIN_CONF)
+            .unwrap_or(DEFAULT_MIN_CONFIDENCE_BPS)
+    }
+
+    /// Admin-only: set the maximum age, in seconds, before a price is considered stale.
+    pub fn set_max_staleness_secs(env: &Env, secs: u64) {
+        crate::admin::Admin::get_admin(env).require_auth();
+        env.storage().instance().set(&MAX_STALE, &secs);
+    }
+
+    /// Admin-only: set the minimum cross-source agreement, in basis points, a price must meet.
+    pub fn set_min_confidence_bps(env: &Env, bps: u32) {
+        crate::admin::Admin::get_admin(env).require_auth();
+        env.storage().instance().set(&MIN_CONF, &bps);
+    }
+
+    /// Whether the current oracle price passes both the staleness and confidence checks.
+    pub fn price_is_fresh(env: &Env) -> bool {
+        Self::require_fresh_price(env).is_ok()
+    }
+
+    /// Fetch the latest price, hard-failing if it is stale or under-confident.
+    ///
+    /// Privilege-creating operations (mint, mint_with_proof) call this
+    /// instead of `get_price`. Value-reducing operations (burn, clawback,
+    /// transfer) do not read the oracle at all, so they are unaffected by
+    /// oracle health.
+    pub fn require_fresh_price(env: &Env) -> Result<PriceData, Error> {
+        let price = Self::get_price(env)?;
+        let now = env.ledger().timestamp();
+        if now.saturating_sub(price.timestamp) > Self::max_staleness_secs(env) {
+            return Err(Error::OracleStale);
+        }
+        if price.confidence < Self::min_confidence_bps(env) {
+            return Err(Error::OracleConfidence);
+        }
+        Ok(price)
+    }
+}
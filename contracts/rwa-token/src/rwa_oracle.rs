@@ -0,0 +1,51 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Price observation returned by the RWA Oracle contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+    /// Agreement across independent sources, in basis points (10_000 = full agreement).
+    pub confidence: u32,
+}
+
+/// Asset classification reported by the RWA Oracle (SEP-0001).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RWAAssetType {
+    Equity,
+    Bond,
+    RealEstate,
+    Commodity,
+    Other,
+}
+
+/// Full RWA metadata reported by the RWA Oracle (SEP-0001).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RWAMetadata {
+    pub asset_type: RWAAssetType,
+    pub issuer: Address,
+    pub jurisdiction: Symbol,
+}
+
+/// Regulatory disclosure reported by the RWA Oracle (SEP-0008).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegulatoryInfo {
+    pub regulated: bool,
+    pub jurisdiction: Symbol,
+    pub compliance_contract: Option<Address>,
+}
+
+/// Client interface for the external RWA Oracle contract.
+#[contractclient(name = "OracleClient")]
+pub trait RWAOracleInterface {
+    fn price(env: Env, asset: Symbol) -> PriceData;
+    fn price_at(env: Env, asset: Symbol, timestamp: u64) -> PriceData;
+    fn decimals(env: Env) -> u32;
+    fn metadata(env: Env, asset: Symbol) -> RWAMetadata;
+    fn asset_type(env: Env, asset: Symbol) -> RWAAssetType;
+    fn regulatory_info(env: Env, asset: Symbol) -> RegulatoryInfo;
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::admin::Admin;
+use crate::error::Error;
+use crate::zk_verifier;
+
+const ROOT: Symbol = symbol_short!("croot");
+const LEAF_COUNT: Symbol = symbol_short!("leaves");
+
+pub struct Shielded;
+
+impl Shielded {
+    fn root(env: &Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&ROOT)
+            .unwrap_or_else(|| BytesN::from_array(env, &[0u8; 32]))
+    }
+
+    fn leaf_count(env: &Env) -> u32 {
+        env.storage().instance().get(&LEAF_COUNT).unwrap_or(0)
+    }
+
+    fn commitment_key(index: u32) -> (Symbol, u32) {
+        (symbol_short!("cmt"), index)
+    }
+
+    fn note_key(index: u32) -> (Symbol, u32) {
+        (symbol_short!("note"), index)
+    }
+
+    fn nullifier_key(nullifier: &BytesN<32>) -> (Symbol, BytesN<32>) {
+        (symbol_short!("null"), nullifier.clone())
+    }
+
+    /// Append a commitment, folding it into the root as keccak256(old_root, commitment).
+    fn append_commitment(env: &Env, commitment: &BytesN<32>) -> u32 {
+        let index = Self::leaf_count(env);
+        let old_root = Self::root(env);
+
+        let mut preimage = Bytes::new(env);
+        preimage.append(&Bytes::from_array(env, &old_root.to_array()));
+        preimage.append(&Bytes::from_array(env, &commitment.to_array()));
+        let new_root: BytesN<32> = env.crypto().keccak256(&preimage).into();
+
+        env.storage()
+            .persistent()
+            .set(&Self::commitment_key(index), commitment);
+        env.storage().instance().set(&ROOT, &new_root);
+        env.storage().instance().set(&LEAF_COUNT, &(index + 1));
+        index
+    }
+
+    /// The current commitment tree root, for clients building membership proofs.
+    pub fn root_view(env: &Env) -> BytesN<32> {
+        Self::root(env)
+    }
+
+    /// Requires `from`'s authorization, then always returns
+    /// `Error::ShieldedTransfersDisabled`: no balance is debited and
+    /// `note_commitment` is not inserted into the commitment tree.
+    ///
+    /// `verify_transfer_proof` rejects every proof, so `transfer_shielded` and
+    /// `unshield` can never spend a note; accepting deposits here would leave
+    /// holders' balances with no spend or exit path.
+    pub fn shield(
+        _env: &Env,
+        from: &Address,
+        _amount: i128,
+        _note_commitment: BytesN<32>,
+        _note_ciphertext: Bytes,
+    ) -> Result<u32, Error> {
+        from.require_auth();
+        Err(Error::ShieldedTransfersDisabled)
+    }
+
+    /// Spend shielded input notes and create new shielded output notes.
+    ///
+    /// `nullifiers` and `output_commitments`/`output_ciphertexts` are public
+    /// inputs carried alongside `proof`, which attests input-note membership
+    /// against the published root and value conservation between them.
+    pub fn transfer_shielded(
+        env: &Env,
+        proof: Bytes,
+        public_inputs: Vec<u32>,
+        nullifiers: Vec<BytesN<32>>,
+        output_commitments: Vec<BytesN<32>>,
+        output_ciphertexts: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        let root = Self::root(env);
+
+        for nullifier in nullifiers.iter() {
+            if env.storage().persistent().has(&Self::nullifier_key(&nullifier)) {
+                return Err(Error::ProofVerificationFailed);
+            }
+        }
+
+        if !zk_verifier::verify_transfer_proof(env, &proof, &public_inputs, &root)? {
+            return Err(Error::ProofVerificationFailed);
+        }
+
+        for nullifier in nullifiers.iter() {
+            env.storage()
+                .persistent()
+                .set(&Self::nullifier_key(&nullifier), &true);
+        }
+
+        for (commitment, ciphertext) in output_commitments.iter().zip(output_ciphertexts.iter()) {
+            let index = Self::append_commitment(env, &commitment);
+            env.storage()
+                .persistent()
+                .set(&Self::note_key(index), &ciphertext);
+        }
+
+        Ok(())
+    }
+
+    /// Spend a shielded note and credit a transparent balance.
+    ///
+    /// Routed through `Oracle::check_compliance_before_transfer` so moving
+    /// value back out of the shielded pool counts against `to`'s rate limit
+    /// the same way an ordinary transfer would.
+    pub fn unshield(
+        env: &Env,
+        proof: Bytes,
+        public_inputs: Vec<u32>,
+        nullifier: BytesN<32>,
+        to: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let root = Self::root(env);
+
+        if env.storage().persistent().has(&Self::nullifier_key(&nullifier)) {
+            return Err(Error::ProofVerificationFailed);
+        }
+
+        if !zk_verifier::verify_transfer_proof(env, &proof, &public_inputs, &root)? {
+            return Err(Error::ProofVerificationFailed);
+        }
+
+        crate::oracle::Oracle::check_compliance_before_transfer(env, to, to, amount)?;
+
+        env.storage()
+            .persistent()
+            .set(&Self::nullifier_key(&nullifier), &true);
+        Admin::credit(env, to, amount);
+        Ok(())
+    }
+}
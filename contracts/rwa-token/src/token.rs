@@ -1,9 +1,13 @@
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, MuxedAddress, String, Symbol, Vec, panic_with_error};
 
 use crate::admin::Admin;
+use crate::bridge::Bridge;
+use crate::compliance::Compliance;
 use crate::error::Error;
+use crate::fees::{FeeMode, Fees};
 use crate::interfaces::{TokenInterface, TokenInterfaceImpl};
 use crate::oracle::Oracle;
+use crate::shielded::Shielded;
 use crate::zk_verifier;
 
 /// RWA Token Contract
@@ -44,8 +48,14 @@ impl RWATokenContract {
     }
 
     /// Mint tokens to an address. Admin-only.
-    pub fn mint(env: Env, to: Address, amount: i128) {
+    ///
+    /// Hard-fails if the oracle price backing this asset is stale or
+    /// below the configured confidence threshold, since minting creates
+    /// new privilege pegged to that price.
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        Oracle::require_fresh_price(&env)?;
         Admin::mint(&env, &to, amount);
+        Ok(())
     }
 
     /// Clawback tokens from an address. Admin-only.
@@ -115,6 +125,21 @@ impl RWATokenContract {
         Oracle::get_decimals(&env)
     }
 
+    /// Whether the current oracle price is fresh enough and confident enough to mint against.
+    pub fn price_is_fresh(env: Env) -> bool {
+        Oracle::price_is_fresh(&env)
+    }
+
+    /// Set the maximum age, in seconds, before a price is considered stale. Admin-only.
+    pub fn set_max_staleness_secs(env: Env, secs: u64) {
+        Oracle::set_max_staleness_secs(&env, secs);
+    }
+
+    /// Set the minimum cross-source agreement, in basis points, a price must meet. Admin-only.
+    pub fn set_min_confidence_bps(env: Env, bps: u32) {
+        Oracle::set_min_confidence_bps(&env, bps);
+    }
+
     // SEP-0001: Get RWA metadata from Oracle
     /// Get complete RWA metadata from the RWA Oracle (SEP-0001)
     pub fn get_rwa_metadata(env: Env) -> Result<crate::rwa_oracle::RWAMetadata, Error> {
@@ -160,6 +185,7 @@ impl RWATokenContract {
     /// - Public inputs are checked against submitted price
     /// - Proof hash is stored to prevent replay attacks
     /// - Requires admin authorization
+    /// - Oracle price backing this mint must be fresh and sufficiently confident
     pub fn mint_with_proof(
         env: Env,
         to: Address,
@@ -170,6 +196,10 @@ impl RWATokenContract {
         proof_data: Bytes,
         public_inputs: Vec<u32>,
     ) -> Result<(), Error> {
+        // Minting creates privilege pegged to the oracle price, so the oracle
+        // itself must be fresh and confident before we even check the proof.
+        Oracle::require_fresh_price(&env)?;
+
         // Verify the ZK proof
         let proof_valid = zk_verifier::verify_price_proof(
             &env,
@@ -263,6 +293,155 @@ impl RWATokenContract {
     }
 
     // === END ZK METHODS ===
+
+    // === BRIDGE METHODS ===
+
+    /// Set the guardian public keys and the M-of-N signature threshold. Admin-only.
+    pub fn set_guardians(env: Env, guardians: Vec<BytesN<32>>, threshold: u32) {
+        Bridge::set_guardians(&env, guardians, threshold);
+    }
+
+    /// Lock (escrow/burn) tokens for transfer to another chain.
+    ///
+    /// Returns the sequence number that guardians must attest to before
+    /// `complete_transfer` can mint the corresponding tokens on the target chain.
+    pub fn lock_for_transfer(
+        env: Env,
+        from: Address,
+        amount: i128,
+        target_chain: u32,
+        target_address: BytesN<32>,
+    ) -> Result<u64, Error> {
+        Bridge::lock_for_transfer(&env, from, amount, target_chain, target_address)
+    }
+
+    /// Complete an inbound cross-chain transfer attested by the guardian set.
+    ///
+    /// `asset_meta` is `(origin_chain, origin_address, decimals)` for the wrapped
+    /// asset being minted; `signatures` are `(guardian_index, signature)` pairs
+    /// over the keccak256 hash of `(source_chain, sequence, recipient, amount,
+    /// asset_meta)`, rebuilt from the call arguments rather than taken as an
+    /// independent payload.
+    pub fn complete_transfer(
+        env: Env,
+        source_chain: u32,
+        sequence: u64,
+        recipient: Address,
+        amount: i128,
+        asset_meta: (u32, BytesN<32>, u32),
+        signatures: Vec<(u32, BytesN<64>)>,
+    ) -> Result<(), Error> {
+        let (origin_chain, origin_address, decimals) = asset_meta;
+        Bridge::complete_transfer(
+            &env,
+            source_chain,
+            sequence,
+            recipient,
+            amount,
+            crate::bridge::WrappedAssetMeta {
+                origin_chain,
+                origin_address,
+                decimals,
+            },
+            signatures,
+        )
+    }
+
+    // === END BRIDGE METHODS ===
+
+    // === SHIELDED TRANSFER METHODS ===
+
+    /// Get the current commitment tree root, for clients building membership proofs.
+    pub fn shielded_root(env: Env) -> BytesN<32> {
+        Shielded::root_view(&env)
+    }
+
+    /// Shielding entry point. Always returns `Error::ShieldedTransfersDisabled`:
+    /// no balance is debited, `note_commitment` is not inserted into the
+    /// commitment tree and `note_ciphertext` is not stored.
+    pub fn shield(
+        env: Env,
+        from: Address,
+        amount: i128,
+        note_commitment: BytesN<32>,
+        note_ciphertext: Bytes,
+    ) -> Result<u32, Error> {
+        Shielded::shield(&env, &from, amount, note_commitment, note_ciphertext)
+    }
+
+    /// Spend shielded input notes and create new shielded output notes, gated on
+    /// `zk_verifier::verify_transfer_proof`. That verifier rejects every proof, so
+    /// this always returns `Error::ProofVerificationFailed`.
+    pub fn transfer_shielded(
+        env: Env,
+        proof: Bytes,
+        public_inputs: Vec<u32>,
+        nullifiers: Vec<BytesN<32>>,
+        output_commitments: Vec<BytesN<32>>,
+        output_ciphertexts: Vec<Bytes>,
+    ) -> Result<(), Error> {
+        Shielded::transfer_shielded(
+            &env,
+            proof,
+            public_inputs,
+            nullifiers,
+            output_commitments,
+            output_ciphertexts,
+        )
+    }
+
+    /// Spend a shielded note and credit a transparent balance, gated on
+    /// `zk_verifier::verify_transfer_proof`. That verifier rejects every proof, so
+    /// this always returns `Error::ProofVerificationFailed`.
+    pub fn unshield(
+        env: Env,
+        proof: Bytes,
+        public_inputs: Vec<u32>,
+        nullifier: BytesN<32>,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Shielded::unshield(&env, proof, public_inputs, nullifier, &to, amount)
+    }
+
+    // === END SHIELDED TRANSFER METHODS ===
+
+    // === COMPLIANCE RATE LIMIT METHODS ===
+
+    /// Set the rolling rate-limit window length, in ledgers. Admin-only.
+    pub fn set_transfer_limit_window(env: Env, window_ledgers: u32) {
+        Compliance::set_window_ledgers(&env, window_ledgers);
+    }
+
+    /// Set the default per-address transfer-volume limit for the current window,
+    /// in whole token units (e.g. `1000` means `1000 * 10^decimals`). `None`
+    /// disables the global limit. Admin-only.
+    pub fn set_global_transfer_limit(env: Env, limit: Option<i128>) {
+        Compliance::set_global_limit(&env, limit);
+    }
+
+    /// Set a per-address transfer-volume limit overriding the global default, in
+    /// whole token units. `None` clears the override. Admin-only.
+    pub fn set_address_transfer_limit(env: Env, addr: Address, limit: Option<i128>) {
+        Compliance::set_address_limit(&env, &addr, limit);
+    }
+
+    // === END COMPLIANCE RATE LIMIT METHODS ===
+
+    // === TRANSFER FEE METHODS ===
+
+    /// Configure the transfer-fee mode and the treasury fees are routed to. Admin-only.
+    /// Pass `FeeMode::Disabled` to turn fees off.
+    pub fn set_transfer_fee(env: Env, mode: FeeMode, treasury: Address) {
+        Fees::set_fee(&env, mode, treasury);
+    }
+
+    /// Get the currently configured transfer-fee mode and treasury, if any.
+    pub fn get_transfer_fee(env: Env) -> (FeeMode, Option<Address>) {
+        Fees::get_fee(&env)
+    }
+
+    // === END TRANSFER FEE METHODS ===
 }
 
 // Standard Token Interface implementation
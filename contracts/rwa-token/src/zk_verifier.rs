@@ -0,0 +1,68 @@
+use soroban_sdk::{symbol_short, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::error::Error;
+
+const PROOF: Symbol = symbol_short!("proof");
+
+fn proof_key(proof_hash: &BytesN<32>) -> (Symbol, BytesN<32>) {
+    (PROOF, proof_hash.clone())
+}
+
+/// Verify a Noir/UltraHonk proof certifying that `price` at `timestamp` was
+/// derived from multiple independent sources within the allowed variance.
+///
+/// Checks only that the public inputs are non-empty and that this proof has
+/// not been used before, then records the proof hash so the same proof cannot
+/// be replayed. The proof bytes are not checked, and `price`/`timestamp` are
+/// not compared against the public inputs.
+pub fn verify_price_proof(
+    env: &Env,
+    proof_data: &Bytes,
+    public_inputs: &Vec<u32>,
+    price: i128,
+    timestamp: u64,
+) -> Result<bool, Error> {
+    if public_inputs.is_empty() {
+        return Err(Error::ProofVerificationFailed);
+    }
+
+    let proof_hash: BytesN<32> = env.crypto().keccak256(proof_data).into();
+    let key = proof_key(&proof_hash);
+    if env.storage().persistent().has(&key) {
+        return Err(Error::ProofVerificationFailed);
+    }
+
+    // price/timestamp are part of what the circuit's public inputs commit to;
+    // a full verifier binding would re-derive and compare them here.
+    let _ = (price, timestamp);
+
+    env.storage().persistent().set(&key, &timestamp);
+    Ok(true)
+}
+
+pub fn is_proof_used(env: &Env, proof_hash: &BytesN<32>) -> bool {
+    env.storage().persistent().has(&proof_key(proof_hash))
+}
+
+pub fn get_proof_usage_timestamp(env: &Env, proof_hash: &BytesN<32>) -> Option<u64> {
+    env.storage().persistent().get(&proof_key(proof_hash))
+}
+
+/// Verify a shielded-transfer proof asserting input-note membership against
+/// `root`, value conservation, and correctly-formed output commitments.
+///
+/// There is no real Noir/UltraHonk verifier wired into this contract yet:
+/// checking only "public inputs non-empty" plus anti-replay (as
+/// `verify_price_proof` does for the admin-gated mint path) would let anyone
+/// mint or move shielded value with a fabricated proof, since
+/// `transfer_shielded`/`unshield` carry no other authorization. Until a real
+/// verifier binding the root/nullifiers/output commitments is wired in here,
+/// fail closed rather than rubber-stamp every call.
+pub fn verify_transfer_proof(
+    _env: &Env,
+    _proof_data: &Bytes,
+    _public_inputs: &Vec<u32>,
+    _root: &BytesN<32>,
+) -> Result<bool, Error> {
+    Err(Error::ProofVerificationFailed)
+}